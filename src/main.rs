@@ -14,13 +14,16 @@ use crossterm::{
     ExecutableCommand,
 };
 use http::Uri;
+use serde::Deserialize;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Cell, List, ListItem, ListState, Row, Table, TableState},
+    widgets::{
+        Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+    },
     Frame, Terminal,
 };
 
@@ -73,7 +76,7 @@ async fn run(args: &Cli) -> Result<()> {
 async fn query(args: &QueryDynamoArgs, endpoint: Option<&str>) -> Result<()> {
     println!("Querying dymamo: {:?}", args.table_name);
     let ep = endpoint.unwrap_or("").to_string();
-    let client = DynamoClient::new(&ep).await;
+    let client = DynamoClient::new(&ep).await?;
     let output = client
         .client()
         .scan()
@@ -109,10 +112,14 @@ async fn run_ui(endpoint: Option<&str>) -> Result<()> {
     let (mut ui_tx, mut ui_rx) = tokio::sync::mpsc::channel(1);
 
     let ep = endpoint.unwrap_or("").to_string();
-    let mut app = App::new(ui_tx.clone(), &ep).await;
+    let mut app = App::new(ui_tx.clone(), &ep).await?;
 
-    let client = DynamoClient::new(&ep).await;
-    let _ = refresh_table_list(client, ui_tx.clone());
+    let config = load_config().unwrap_or_default();
+    app.load_connections(&config.connection);
+
+    if let Ok(client) = DynamoClient::new(&ep).await {
+        let _ = refresh_table_list(client, ui_tx.clone());
+    }
 
     loop {
         terminal.draw(|rect| {
@@ -123,7 +130,35 @@ async fn run_ui(endpoint: Option<&str>) -> Result<()> {
         Some(event) = rx.recv() => {
             match event {
                 Event::Input(event) =>
-                    match event.code {
+                    if app.detail.is_some() {
+                        match event.code {
+                            KeyCode::Char('j') => app.detail_move_down(),
+                            KeyCode::Char('k') => app.detail_move_up(),
+                            KeyCode::Enter => app.detail_descend(),
+                            KeyCode::Esc | KeyCode::Char('q') => app.detail_back(),
+                            _ => {}
+                        }
+                    } else {
+                    match app.input_mode {
+                        InputMode::Editing => match event.code {
+                            KeyCode::Enter => {
+                                match app.editing_purpose {
+                                    Some(EditPurpose::Export) => app.apply_export(),
+                                    _ => app.apply_filter().await?,
+                                }
+                            },
+                            KeyCode::Char(c) => {
+                                app.input.push(c);
+                            },
+                            KeyCode::Backspace => {
+                                app.input.pop();
+                            },
+                            KeyCode::Esc => {
+                                app.cancel_filter();
+                            },
+                            _ => {}
+                        },
+                        InputMode::Normal => match event.code {
                         KeyCode::Char('q') => {
                             disable_raw_mode()?;
                                         io::stdout().execute(LeaveAlternateScreen)?;
@@ -131,21 +166,44 @@ async fn run_ui(endpoint: Option<&str>) -> Result<()> {
                             break;
                     },
 
+                    KeyCode::Char('/') => {
+                        app.start_filter();
+                    },
+                    KeyCode::Char('e') => {
+                        app.start_export();
+                    },
                     KeyCode::Char('j') => {
-                        let _ = app.move_down();
+                        app.move_down().await?;
                     },
                     KeyCode::Char('k') => {
                         let _ = app.move_up();
                     },
+                    KeyCode::Char('h') => {
+                        app.move_left();
+                    },
+                    KeyCode::Char('l') => {
+                        app.move_right();
+                    },
                     KeyCode::Enter => {
-                        app.table_selected().await?;
+                        match app.active {
+                            ActiveView::Connections => app.connection_selected().await?,
+                            ActiveView::TableData => app.open_detail(),
+                            _ => app.tree_enter().await?,
+                        }
                     },
                     KeyCode::Tab => {
                         let _ = app.toggle_active_pane();
+                    },
+                    KeyCode::Char(' ') => {
+                        if matches!(app.active, ActiveView::TableList) {
+                            app.toggle_tree_node().await?;
+                        }
                     },
                         _ => {}
 
                     }
+                    }
+                    }
 
                 Event::Tick => {}
             }
@@ -153,7 +211,29 @@ async fn run_ui(endpoint: Option<&str>) -> Result<()> {
             Some(ui_event) = ui_rx.recv() => {
                 match ui_event {
                     UIEvent::RefreshDynamoTableList(tables) => app.load_tables(&tables),
-                    UIEvent::LoadTable(name) => load_table(&ep, &name,  ui_tx.clone()).await?,
+                    UIEvent::LoadTable(name) => load_table(&app.active_profile, &name, None, None, ui_tx.clone()).await?,
+                    UIEvent::LoadIndexedTable(name, index) => load_table(&app.active_profile, &name, None, Some(index), ui_tx.clone()).await?,
+                    UIEvent::ApplyFilter(name, filter) => {
+                        let index = app.selected_table.index.clone();
+                        load_table(&app.active_profile, &name, Some(filter), index, ui_tx.clone()).await?
+                    }
+                    UIEvent::LoadMore(name, filter, start_key) => {
+                        let profile = app.active_profile.clone();
+                        let tx = ui_tx.clone();
+                        let index = app.selected_table.index.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = load_more(&profile, &name, filter, index, start_key, tx).await {
+                                println!("{:?}", e);
+                            }
+                        });
+                    }
+                    UIEvent::AppendRows(rows, last_key) => app.append_rows(rows, last_key),
+                    UIEvent::DescribeTable(name) => {
+                        if let Ok(client) = DynamoClient::from_profile(&app.active_profile).await {
+                            let _ = describe_table_indexes(client, name, ui_tx.clone());
+                        }
+                    }
+                    UIEvent::TableIndexes(name, indexes) => app.set_table_indexes(&name, indexes),
                     UIEvent::DisplayTable(table) => app.select_table(table)
                 }
             }
@@ -167,48 +247,249 @@ enum ActiveView {
     None,
     TableList,
     TableData,
+    Connections,
+}
+
+/// A single named DynamoDB target loaded from `nebulous.toml`, e.g.
+///
+/// ```toml
+/// [[connection]]
+/// name = "local"
+/// endpoint = "http://localhost:8000"
+/// region = "us-east-1"
+/// profile = "default"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConnectionProfile {
+    name: String,
+    #[serde(default)]
+    endpoint: String,
+    region: Option<String>,
+    profile: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct Config {
+    #[serde(default)]
+    connection: Vec<ConnectionProfile>,
+}
+
+/// One row of the left-hand table tree: either a top-level table or a
+/// secondary index nested underneath one, lazily revealed on expand.
+#[derive(Debug, Clone)]
+enum TreeItemKind {
+    Table(String),
+    Index { table: String, name: String },
+}
+
+#[derive(Debug, Clone)]
+struct TreeItem {
+    kind: TreeItemKind,
+    indent: usize,
+    collapsed: bool,
+    indexes_loaded: bool,
+    visible: bool,
+}
+
+impl TreeItem {
+    fn table(name: String) -> Self {
+        Self {
+            kind: TreeItemKind::Table(name),
+            indent: 0,
+            collapsed: true,
+            indexes_loaded: false,
+            visible: true,
+        }
+    }
+
+    fn index(table: String, name: String) -> Self {
+        Self {
+            kind: TreeItemKind::Index { table, name },
+            indent: 1,
+            collapsed: false,
+            indexes_loaded: true,
+            visible: true,
+        }
+    }
+}
+
+fn load_config() -> Result<Config> {
+    let dir = dirs::config_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
+    let contents = std::fs::read_to_string(dir.join("nebulous.toml"))?;
+    let config: Config = toml::from_str(&contents)?;
+    Ok(config)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputMode {
+    Normal,
+    Editing,
+}
+
+/// What the shared `input` buffer is being used for while in `InputMode::Editing`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditPurpose {
+    Filter,
+    Export,
 }
 
 struct App {
     dynamo_client: DynamoClient,
     endpoint: String,
+    active_profile: ConnectionProfile,
     tables: ListState,
     table_list: Vec<String>,
+    table_tree: Vec<TreeItem>,
     items: TableState,
     active: ActiveView,
     io_tx: Option<Sender<UIEvent>>,
     selected_table: NebTable,
+    current_table: Option<String>,
+    input_mode: InputMode,
+    input: String,
+    editing_purpose: Option<EditPurpose>,
+    connections: ListState,
+    connection_list: Vec<ConnectionProfile>,
+    loading_more: bool,
+    detail: Option<DetailView>,
+    col_offset: usize,
 }
 
 impl App {
-    pub async fn new(io_tx: Sender<UIEvent>, endpoint: &str) -> Self {
+    pub async fn new(io_tx: Sender<UIEvent>, endpoint: &str) -> Result<Self> {
         let mut table_list_state = ListState::default();
         table_list_state.select(Some(0));
 
         let mut table_data_state = TableState::default();
         table_data_state.select(None);
 
-        Self {
+        Ok(Self {
             io_tx: Some(io_tx),
-            dynamo_client: DynamoClient::new(endpoint).await,
-            endpoint: String::new(),
+            dynamo_client: DynamoClient::new(endpoint).await?,
+            endpoint: endpoint.to_string(),
+            active_profile: ConnectionProfile {
+                name: "default".to_string(),
+                endpoint: endpoint.to_string(),
+                region: None,
+                profile: None,
+            },
             tables: table_list_state,
             items: table_data_state,
             table_list: vec![],
+            table_tree: vec![],
             active: ActiveView::TableList,
             selected_table: NebTable::default(),
-        }
+            current_table: None,
+            input_mode: InputMode::Normal,
+            input: String::new(),
+            editing_purpose: None,
+            connections: ListState::default(),
+            connection_list: vec![],
+            loading_more: false,
+            detail: None,
+            col_offset: 0,
+        })
     }
 
     pub fn load_tables(&mut self, tables: &[String]) {
         self.table_list = tables.to_vec();
+        self.table_tree = tables.iter().cloned().map(TreeItem::table).collect();
+        if self.tables.selected().is_none() && !self.table_tree.is_empty() {
+            self.tables.select(Some(0));
+        }
+    }
+
+    /// Index positions of `table_tree` entries currently shown in the pane
+    /// (a table's index children are hidden while it is collapsed).
+    fn visible_tree_indices(&self) -> Vec<usize> {
+        self.table_tree
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.visible)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn recompute_tree_visibility(&mut self) {
+        let mut parent_collapsed = false;
+        for item in &mut self.table_tree {
+            match &item.kind {
+                TreeItemKind::Table(_) => {
+                    item.visible = true;
+                    parent_collapsed = item.collapsed;
+                }
+                TreeItemKind::Index { .. } => item.visible = !parent_collapsed,
+            }
+        }
+    }
+
+    fn selected_tree_index(&self) -> Option<usize> {
+        let visible = self.visible_tree_indices();
+        let selected = self.tables.selected()?;
+        visible.get(selected).copied()
     }
 
-    pub fn move_down(&mut self) -> Result<()> {
+    pub fn set_table_indexes(&mut self, table: &str, indexes: Vec<String>) {
+        let pos = self.table_tree.iter().position(
+            |item| matches!(&item.kind, TreeItemKind::Table(name) if name == table),
+        );
+        let pos = match pos {
+            Some(pos) => pos,
+            None => return,
+        };
+
+        self.table_tree[pos].indexes_loaded = true;
+
+        let mut remove_at = pos + 1;
+        while remove_at < self.table_tree.len()
+            && matches!(self.table_tree[remove_at].kind, TreeItemKind::Index { .. })
+        {
+            self.table_tree.remove(remove_at);
+        }
+
+        for (offset, name) in indexes.into_iter().enumerate() {
+            self.table_tree
+                .insert(pos + 1 + offset, TreeItem::index(table.to_string(), name));
+        }
+
+        self.recompute_tree_visibility();
+    }
+
+    /// Toggles collapse on the selected table node (Space in the table list),
+    /// lazily fetching its secondary indexes the first time it is expanded.
+    pub async fn toggle_tree_node(&mut self) -> Result<()> {
+        let idx = match self.selected_tree_index() {
+            Some(idx) => idx,
+            None => return Ok(()),
+        };
+
+        let (name, indexes_loaded) = match &self.table_tree[idx].kind {
+            TreeItemKind::Table(name) => (name.clone(), self.table_tree[idx].indexes_loaded),
+            TreeItemKind::Index { .. } => return Ok(()),
+        };
+
+        self.table_tree[idx].collapsed = !self.table_tree[idx].collapsed;
+        self.recompute_tree_visibility();
+
+        if !self.table_tree[idx].collapsed && !indexes_loaded {
+            self.dispatch(UIEvent::DescribeTable(name)).await?;
+        }
+
+        Ok(())
+    }
+
+    pub fn load_connections(&mut self, connections: &[ConnectionProfile]) {
+        self.connection_list = connections.to_vec();
+    }
+
+    pub async fn move_down(&mut self) -> Result<()> {
         match self.active {
             ActiveView::TableList => {
+                let visible_count = self.visible_tree_indices().len();
                 if let Some(selected) = self.tables.selected() {
-                    if selected >= self.table_list.len() {
+                    if visible_count == 0 {
+                        self.tables.select(None)
+                    } else if selected + 1 >= visible_count {
                         self.tables.select(Some(0))
                     } else {
                         self.tables.select(Some(selected + 1))
@@ -217,13 +498,32 @@ impl App {
             }
             ActiveView::TableData => {
                 if let Some(selected) = self.items.selected() {
-                    if selected >= self.selected_table.rows.len() {
-                        self.items.select(Some(1))
+                    if selected + 1 >= self.selected_table.rows.len() {
+                        if !self.loading_more {
+                            if let (Some(name), Some(start_key)) = (
+                                self.current_table.clone(),
+                                self.selected_table.last_evaluated_key.clone(),
+                            ) {
+                                self.loading_more = true;
+                                let filter = self.selected_table.filter.clone();
+                                self.dispatch(UIEvent::LoadMore(name, filter, start_key))
+                                    .await?;
+                            }
+                        }
                     } else {
                         self.items.select(Some(selected + 1))
                     }
                 }
             }
+            ActiveView::Connections => {
+                if let Some(selected) = self.connections.selected() {
+                    if selected >= self.connection_list.len().saturating_sub(1) {
+                        self.connections.select(Some(0))
+                    } else {
+                        self.connections.select(Some(selected + 1))
+                    }
+                }
+            }
             _ => {}
         }
         Ok(())
@@ -232,11 +532,14 @@ impl App {
     pub fn move_up(&mut self) -> Result<()> {
         match self.active {
             ActiveView::TableList => {
+                let visible_count = self.visible_tree_indices().len();
                 if let Some(selected) = self.tables.selected() {
-                    if selected > 0 {
+                    if visible_count == 0 {
+                        self.tables.select(None)
+                    } else if selected > 0 {
                         self.tables.select(Some(selected - 1))
                     } else {
-                        self.tables.select(Some(self.table_list.len() - 1))
+                        self.tables.select(Some(visible_count - 1))
                     }
                 }
             }
@@ -249,16 +552,52 @@ impl App {
                     }
                 }
             }
+            ActiveView::Connections => {
+                if let Some(selected) = self.connections.selected() {
+                    if selected > 0 {
+                        self.connections.select(Some(selected - 1))
+                    } else {
+                        self.connections
+                            .select(Some(self.connection_list.len().saturating_sub(1)))
+                    }
+                }
+            }
             _ => {}
         }
         Ok(())
     }
 
-    pub async fn table_selected(&mut self) -> Result<()> {
-        if let Some(selected) = self.tables.selected() {
-            if selected < self.table_list.len() {
-                let name = &self.table_list[selected];
-                self.dispatch(UIEvent::LoadTable(name.clone())).await?;
+    pub fn move_left(&mut self) {
+        if matches!(self.active, ActiveView::TableData) && self.col_offset > 0 {
+            self.col_offset -= 1;
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if matches!(self.active, ActiveView::TableData)
+            && self.col_offset + 1 < self.selected_table.headers.len()
+        {
+            self.col_offset += 1;
+        }
+    }
+
+    /// Enter on the table tree: a table node expands/collapses its indexes
+    /// and loads a plain scan; an index node loads a scan against that index.
+    pub async fn tree_enter(&mut self) -> Result<()> {
+        let idx = match self.selected_tree_index() {
+            Some(idx) => idx,
+            None => return Ok(()),
+        };
+
+        match self.table_tree[idx].kind.clone() {
+            TreeItemKind::Table(name) => {
+                self.toggle_tree_node().await?;
+                self.current_table = Some(name.clone());
+                self.dispatch(UIEvent::LoadTable(name)).await?;
+            }
+            TreeItemKind::Index { table, name } => {
+                self.current_table = Some(table.clone());
+                self.dispatch(UIEvent::LoadIndexedTable(table, name)).await?;
             }
         }
         Ok(())
@@ -268,6 +607,120 @@ impl App {
         self.selected_table = table;
     }
 
+    pub fn append_rows(
+        &mut self,
+        rows: Vec<TableRow>,
+        last_evaluated_key: Option<HashMap<String, aws_sdk_dynamodb::model::AttributeValue>>,
+    ) {
+        self.selected_table.rows.extend(rows);
+        self.selected_table.last_evaluated_key = last_evaluated_key;
+        self.loading_more = false;
+    }
+
+    pub fn open_detail(&mut self) {
+        if let Some(selected) = self.items.selected() {
+            if let Some(row) = self.selected_table.rows.get(selected) {
+                let map: HashMap<String, ItemValue> = row
+                    .data
+                    .iter()
+                    .map(|kv| (kv.key.clone(), kv.value.clone()))
+                    .collect();
+                self.detail = Some(DetailView::new("item".to_string(), ItemValue::Map(map)));
+            }
+        }
+    }
+
+    pub fn detail_back(&mut self) {
+        let should_close = match &mut self.detail {
+            Some(detail) => !detail.back(),
+            None => false,
+        };
+        if should_close {
+            self.detail = None;
+        }
+    }
+
+    pub fn detail_descend(&mut self) {
+        if let Some(detail) = &mut self.detail {
+            detail.descend();
+        }
+    }
+
+    pub fn detail_move_down(&mut self) {
+        if let Some(detail) = &mut self.detail {
+            detail.move_down();
+        }
+    }
+
+    pub fn detail_move_up(&mut self) {
+        if let Some(detail) = &mut self.detail {
+            detail.move_up();
+        }
+    }
+
+    pub async fn connection_selected(&mut self) -> Result<()> {
+        if let Some(selected) = self.connections.selected() {
+            if selected < self.connection_list.len() {
+                let profile = self.connection_list[selected].clone();
+                match DynamoClient::from_profile(&profile).await {
+                    Ok(dynamo_client) => {
+                        self.endpoint = profile.endpoint.clone();
+                        self.active_profile = profile.clone();
+
+                        if let Some(io_tx) = &self.io_tx {
+                            let _ = refresh_table_list(dynamo_client.clone(), io_tx.clone());
+                        }
+                        self.dynamo_client = dynamo_client;
+                    }
+                    Err(e) => println!("{:?}", e),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn start_filter(&mut self) {
+        self.input.clear();
+        self.editing_purpose = Some(EditPurpose::Filter);
+        self.input_mode = InputMode::Editing;
+    }
+
+    pub fn start_export(&mut self) {
+        self.input.clear();
+        self.editing_purpose = Some(EditPurpose::Export);
+        self.input_mode = InputMode::Editing;
+    }
+
+    pub fn cancel_filter(&mut self) {
+        self.input.clear();
+        self.editing_purpose = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub async fn apply_filter(&mut self) -> Result<()> {
+        self.input_mode = InputMode::Normal;
+        self.editing_purpose = None;
+        if let Some(name) = self.current_table.clone() {
+            let filter = self.input.clone();
+            self.dispatch(UIEvent::ApplyFilter(name, filter)).await?;
+        }
+        self.input.clear();
+        Ok(())
+    }
+
+    pub fn apply_export(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.editing_purpose = None;
+        let path = self.input.clone();
+        self.input.clear();
+        if path.is_empty() || self.selected_table.headers.is_empty() {
+            return;
+        }
+        if let Err(e) = export_table(&self.selected_table, &path) {
+            println!("{:?}", e);
+        }
+    }
+
     async fn dispatch(&self, action: UIEvent) -> Result<()> {
         if let Some(io_tx) = &self.io_tx {
             let _ = io_tx.send(action).await;
@@ -284,7 +737,13 @@ impl App {
                     self.items.select(Some(0));
                 }
             }
-            ActiveView::TableData => self.active = ActiveView::TableList,
+            ActiveView::TableData => {
+                self.active = ActiveView::Connections;
+                if self.connections.selected().is_none() {
+                    self.connections.select(Some(0));
+                }
+            }
+            ActiveView::Connections => self.active = ActiveView::TableList,
             _ => self.active = ActiveView::TableList,
         }
 
@@ -306,6 +765,46 @@ fn refresh_table_list(client: DynamoClient, tx: tokio::sync::mpsc::Sender<UIEven
     Ok(())
 }
 
+/// Lazily fetches a table's GSIs/LSIs so the table tree can reveal them as
+/// index children on first expand.
+fn describe_table_indexes(
+    client: DynamoClient,
+    table_name: String,
+    tx: tokio::sync::mpsc::Sender<UIEvent>,
+) -> Result<()> {
+    tokio::spawn(async move {
+        match client
+            .client()
+            .describe_table()
+            .table_name(&table_name)
+            .send()
+            .await
+        {
+            Ok(output) => {
+                let mut names = vec![];
+                if let Some(desc) = output.table() {
+                    if let Some(gsis) = desc.global_secondary_indexes() {
+                        names.extend(
+                            gsis.iter()
+                                .filter_map(|i| i.index_name().map(|n| n.to_string())),
+                        );
+                    }
+                    if let Some(lsis) = desc.local_secondary_indexes() {
+                        names.extend(
+                            lsis.iter()
+                                .filter_map(|i| i.index_name().map(|n| n.to_string())),
+                        );
+                    }
+                }
+                let _ = tx.send(UIEvent::TableIndexes(table_name, names)).await;
+            }
+            Err(e) => println!("{:?}", e),
+        }
+    });
+
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 enum ItemValue {
     Null,
@@ -329,6 +828,30 @@ impl fmt::Display for ItemValue {
     }
 }
 
+impl ItemValue {
+    /// Recursively walks this value, pushing a `(depth, key, value)` line for
+    /// itself and every nested `Map`/`List` entry, so a detail pane can
+    /// pretty-print the whole tree without re-deriving indentation per line.
+    fn flatten(&self, depth: usize, key: &str, out: &mut Vec<(usize, String, ItemValue)>) {
+        out.push((depth, key.to_string(), self.clone()));
+        match self {
+            ItemValue::Map(m) => {
+                let mut keys: Vec<&String> = m.keys().collect();
+                keys.sort();
+                for k in keys {
+                    m[k].flatten(depth + 1, k, out);
+                }
+            }
+            ItemValue::List(l) => {
+                for (i, v) in l.iter().enumerate() {
+                    v.flatten(depth + 1, &i.to_string(), out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 impl From<aws_sdk_dynamodb::model::AttributeValue> for ItemValue {
     fn from(item: aws_sdk_dynamodb::model::AttributeValue) -> Self {
         match item {
@@ -351,6 +874,100 @@ impl From<aws_sdk_dynamodb::model::AttributeValue> for ItemValue {
     }
 }
 
+impl From<&ItemValue> for serde_json::Value {
+    fn from(value: &ItemValue) -> Self {
+        match value {
+            ItemValue::Null => serde_json::Value::Null,
+            ItemValue::String(s) => serde_json::Value::String(s.clone()),
+            ItemValue::Number(n) => serde_json::Value::from(*n),
+            ItemValue::Bool(b) => serde_json::Value::Bool(*b),
+            ItemValue::Map(m) => {
+                serde_json::Value::Object(m.iter().map(|(k, v)| (k.clone(), v.into())).collect())
+            }
+            ItemValue::List(l) => serde_json::Value::Array(l.iter().map(|v| v.into()).collect()),
+        }
+    }
+}
+
+/// Drives the pop-up pane that lets a user drill into a nested `Map`/`List`
+/// cell. `stack` holds the ancestors of `current` (label, node, selected
+/// index) so `back` can restore them; `current`/`current_label` are what is
+/// actually pretty-printed right now.
+#[derive(Debug, Clone)]
+struct DetailView {
+    stack: Vec<(String, ItemValue, usize)>,
+    current_label: String,
+    current: ItemValue,
+    selected: usize,
+}
+
+impl DetailView {
+    fn new(label: String, value: ItemValue) -> Self {
+        Self {
+            stack: vec![],
+            current_label: label,
+            current: value,
+            selected: 0,
+        }
+    }
+
+    fn lines(&self) -> Vec<(usize, String, ItemValue)> {
+        let mut out = vec![];
+        self.current.flatten(0, &self.current_label, &mut out);
+        out
+    }
+
+    fn breadcrumb_path(&self) -> String {
+        let mut parts: Vec<&str> = self.stack.iter().map(|(l, _, _)| l.as_str()).collect();
+        parts.push(&self.current_label);
+        parts.join(" > ")
+    }
+
+    fn move_down(&mut self) {
+        let len = self.lines().len();
+        if len > 0 {
+            self.selected = (self.selected + 1) % len;
+        }
+    }
+
+    fn move_up(&mut self) {
+        let len = self.lines().len();
+        if len > 0 {
+            self.selected = if self.selected == 0 {
+                len - 1
+            } else {
+                self.selected - 1
+            };
+        }
+    }
+
+    fn descend(&mut self) {
+        if let Some((_, key, value)) = self.lines().into_iter().nth(self.selected) {
+            if matches!(value, ItemValue::Map(_) | ItemValue::List(_)) {
+                self.stack
+                    .push((self.current_label.clone(), self.current.clone(), self.selected));
+                self.current_label = key;
+                self.current = value;
+                self.selected = 0;
+            }
+        }
+    }
+
+    /// Pops one level back up the breadcrumb stack. Returns `false` when
+    /// already at the root, telling the caller to close the pane instead.
+    fn back(&mut self) -> bool {
+        match self.stack.pop() {
+            Some((label, node, selected)) => {
+                self.current_label = label;
+                self.current = node;
+                self.selected = selected;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct KV {
     key: String,
@@ -392,28 +1009,158 @@ impl From<&HashMap<String, aws_sdk_dynamodb::model::AttributeValue>> for TableRo
 pub struct NebTable {
     rows: Vec<TableRow>,
     headers: Vec<String>,
+    last_evaluated_key: Option<HashMap<String, aws_sdk_dynamodb::model::AttributeValue>>,
+    filter: Option<String>,
+    index: Option<String>,
 }
 
 impl NebTable {
-    fn new(headers: Vec<String>, rows: Vec<TableRow>) -> Self {
-        Self { headers, rows }
+    fn new(
+        headers: Vec<String>,
+        rows: Vec<TableRow>,
+        last_evaluated_key: Option<HashMap<String, aws_sdk_dynamodb::model::AttributeValue>>,
+        filter: Option<String>,
+        index: Option<String>,
+    ) -> Self {
+        Self {
+            headers,
+            rows,
+            last_evaluated_key,
+            filter,
+            index,
+        }
+    }
+}
+
+/// Serializes the currently loaded table to `path`, choosing JSON or CSV by
+/// file extension.
+fn export_table(table: &NebTable, path: &str) -> Result<()> {
+    let contents = if path.to_lowercase().ends_with(".csv") {
+        export_csv(table)
+    } else {
+        export_json(table)?
+    };
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+fn export_json(table: &NebTable) -> Result<String> {
+    let rows: Vec<serde_json::Value> = table
+        .rows
+        .iter()
+        .map(|row| {
+            serde_json::Value::Object(
+                row.data
+                    .iter()
+                    .map(|kv| (kv.key.clone(), (&kv.value).into()))
+                    .collect(),
+            )
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&rows)?)
+}
+
+fn export_csv(table: &NebTable) -> String {
+    let mut out = String::new();
+    out.push_str(
+        &table
+            .headers
+            .iter()
+            .map(|h| csv_escape(h))
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    out.push('\n');
+
+    for row in &table.rows {
+        let cells: Vec<String> = table
+            .headers
+            .iter()
+            .map(|h| {
+                row.data
+                    .iter()
+                    .find(|kv| &kv.key == h)
+                    .map(|kv| csv_escape(&csv_cell(h, &kv.value)))
+                    .unwrap_or_default()
+            })
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Renders a cell for CSV: scalars use `Display`; `Map`/`List` values are
+/// flattened into `key.path=value` pairs joined by `;` so a nested document
+/// still fits in a single cell.
+fn csv_cell(key: &str, value: &ItemValue) -> String {
+    match value {
+        ItemValue::Map(_) | ItemValue::List(_) => {
+            let mut pairs = vec![];
+            flatten_item(key, value, &mut pairs);
+            pairs
+                .into_iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(";")
+        }
+        other => other.to_string(),
+    }
+}
+
+fn flatten_item(prefix: &str, value: &ItemValue, out: &mut Vec<(String, String)>) {
+    match value {
+        ItemValue::Map(m) => {
+            let mut keys: Vec<&String> = m.keys().collect();
+            keys.sort();
+            for k in keys {
+                flatten_item(&format!("{}.{}", prefix, k), &m[k], out);
+            }
+        }
+        ItemValue::List(l) => {
+            for (i, v) in l.iter().enumerate() {
+                flatten_item(&format!("{}.{}", prefix, i), v, out);
+            }
+        }
+        other => out.push((prefix.to_string(), other.to_string())),
+    }
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
     }
 }
 
 async fn load_table(
-    endpoint: &str,
+    profile: &ConnectionProfile,
     table_name: &str,
+    filter: Option<String>,
+    index: Option<String>,
     mut tx: tokio::sync::mpsc::Sender<UIEvent>,
 ) -> Result<()> {
-    let client = DynamoClient::new(endpoint).await;
+    let client = DynamoClient::from_profile(profile).await?;
 
-    let items = client
+    let mut scan = client
         .client()
         .scan()
         .table_name(table_name)
-        .limit(200)
-        .send()
-        .await?;
+        .set_index_name(index.clone())
+        .limit(200);
+
+    if let Some(filter) = filter.as_deref() {
+        if let Some((expression, names, values)) = parse_filter(filter) {
+            scan = scan
+                .filter_expression(expression)
+                .set_expression_attribute_names(Some(names))
+                .set_expression_attribute_values(Some(values));
+        }
+    }
+
+    let items = scan.send().await?;
 
     if let Some(items) = items.items() {
         let headers = if let Some(first) = items.first() {
@@ -423,15 +1170,62 @@ async fn load_table(
         };
 
         let vals: Vec<TableRow> = items.into_iter().map(|i| i.into()).collect();
-        // println!("{:?}", vals);
+        let last_evaluated_key = items.last_evaluated_key().cloned();
 
-        let table = NebTable::new(headers, vals);
+        let table = NebTable::new(headers, vals, last_evaluated_key, filter, index);
         let _ = tx.send(UIEvent::DisplayTable(table)).await;
     }
 
     Ok(())
 }
 
+/// Fetches the next page of a table already on screen, continuing from
+/// `start_key` (the previous response's `LastEvaluatedKey`), and appends the
+/// results rather than replacing the currently displayed rows.
+async fn load_more(
+    profile: &ConnectionProfile,
+    table_name: &str,
+    filter: Option<String>,
+    index: Option<String>,
+    start_key: HashMap<String, aws_sdk_dynamodb::model::AttributeValue>,
+    mut tx: tokio::sync::mpsc::Sender<UIEvent>,
+) -> Result<()> {
+    let client = DynamoClient::from_profile(profile).await?;
+
+    let mut scan = client
+        .client()
+        .scan()
+        .table_name(table_name)
+        .set_index_name(index)
+        .limit(200)
+        .set_exclusive_start_key(Some(start_key));
+
+    if let Some(filter) = filter.as_deref() {
+        if let Some((expression, names, values)) = parse_filter(filter) {
+            scan = scan
+                .filter_expression(expression)
+                .set_expression_attribute_names(Some(names))
+                .set_expression_attribute_values(Some(values));
+        }
+    }
+
+    let items = scan.send().await?;
+
+    let rows: Vec<TableRow> = items
+        .items()
+        .unwrap_or_default()
+        .iter()
+        .map(|i| i.into())
+        .collect();
+    let last_evaluated_key = items.last_evaluated_key().cloned();
+
+    let _ = tx
+        .send(UIEvent::AppendRows(rows, last_evaluated_key))
+        .await;
+
+    Ok(())
+}
+
 // impl Default for App {
 //     fn default() -> Self {
 //         App {
@@ -446,14 +1240,61 @@ async fn load_table(
 pub enum UIEvent {
     RefreshDynamoTableList(Vec<String>),
     LoadTable(String),
+    LoadIndexedTable(String, String),
+    ApplyFilter(String, String),
+    LoadMore(
+        String,
+        Option<String>,
+        HashMap<String, aws_sdk_dynamodb::model::AttributeValue>,
+    ),
+    AppendRows(
+        Vec<TableRow>,
+        Option<HashMap<String, aws_sdk_dynamodb::model::AttributeValue>>,
+    ),
+    DescribeTable(String),
+    TableIndexes(String, Vec<String>),
     DisplayTable(NebTable),
 }
 
+/// Translates a simple `key = value` filter string into a DynamoDB
+/// `filter_expression` plus its placeholder maps, e.g. `status = pending`
+/// becomes `#status = :v0` with `#status -> status` and `:v0 -> "pending"`.
+fn parse_filter(
+    input: &str,
+) -> Option<(
+    String,
+    HashMap<String, String>,
+    HashMap<String, aws_sdk_dynamodb::model::AttributeValue>,
+)> {
+    let (key, value) = input.split_once('=')?;
+    let key = key.trim();
+    let value = value.trim();
+    if key.is_empty() || value.is_empty() {
+        return None;
+    }
+
+    let expression = format!("#{} = :v0", key);
+    let mut names = HashMap::new();
+    names.insert(format!("#{}", key), key.to_string());
+    let mut values = HashMap::new();
+    values.insert(
+        ":v0".to_string(),
+        aws_sdk_dynamodb::model::AttributeValue::S(value.to_string()),
+    );
+
+    Some((expression, names, values))
+}
+
 fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) -> Result<()> {
+    let outer = Layout::default()
+        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+        .direction(Direction::Vertical)
+        .split(f.size());
+
     let chunks = Layout::default()
         .constraints([Constraint::Percentage(20), Constraint::Percentage(80)].as_ref())
         .direction(Direction::Horizontal)
-        .split(f.size());
+        .split(outer[0]);
 
     // let tables = app.dynamo_client.client().list_tables().send().await;
 
@@ -462,20 +1303,44 @@ fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) -> Result<()> {
     //     .highlight_style(Style::default().add_modifier(Modifier::BOLD))
     //     .highlight_symbol("> ");
 
-    let tables: Vec<ListItem> = app
-        .table_list
-        .iter()
-        .map(|t| ListItem::new(vec![Spans::from(Span::from(t.clone()))]))
-        .collect();
-    let tables = List::new(tables)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Dynamo Tables"),
-        )
-        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
-        .highlight_symbol("> ");
-    f.render_stateful_widget(tables, chunks[0], &mut app.tables);
+    if matches!(app.active, ActiveView::Connections) {
+        let connections: Vec<ListItem> = app
+            .connection_list
+            .iter()
+            .map(|c| ListItem::new(vec![Spans::from(Span::from(c.name.clone()))]))
+            .collect();
+        let connections = List::new(connections)
+            .block(Block::default().borders(Borders::ALL).title("Connections"))
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        f.render_stateful_widget(connections, chunks[0], &mut app.connections);
+    } else {
+        let tables: Vec<ListItem> = app
+            .table_tree
+            .iter()
+            .filter(|item| item.visible)
+            .map(|item| {
+                let indent = "  ".repeat(item.indent);
+                let label = match &item.kind {
+                    TreeItemKind::Table(name) => {
+                        let marker = if item.collapsed { "▸" } else { "▾" };
+                        format!("{}{} {}", indent, marker, name)
+                    }
+                    TreeItemKind::Index { name, .. } => format!("{}  {}", indent, name),
+                };
+                ListItem::new(vec![Spans::from(Span::from(label))])
+            })
+            .collect();
+        let tables = List::new(tables)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Dynamo Tables"),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        f.render_stateful_widget(tables, chunks[0], &mut app.tables);
+    }
 
     let table_items: Vec<ListItem> = app
         .selected_table
@@ -491,11 +1356,21 @@ fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) -> Result<()> {
     // let selected_style = Style::default().add_modifier(Modifier::REVERSED);
     let selected_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
     let normal_style = Style::default();
-    let header_cells = app
-        .selected_table
-        .headers
+
+    const COLUMN_SPACING: u16 = 10;
+    const COLUMN_WIDTH: u16 = 20;
+
+    let total_cols = app.selected_table.headers.len();
+    let visible_cols = ((chunks[1].width / (COLUMN_WIDTH + COLUMN_SPACING)).max(1) as usize)
+        .min(total_cols.max(1));
+    app.col_offset = app
+        .col_offset
+        .min(total_cols.saturating_sub(visible_cols));
+    let col_start = app.col_offset;
+    let col_end = (col_start + visible_cols).min(total_cols);
+
+    let header_cells = app.selected_table.headers[col_start..col_end]
         .iter()
-        .take(3)
         .map(|h| Cell::from(h.to_string()).style(Style::default().fg(Color::Red)));
     let header = Row::new(header_cells)
         .style(normal_style)
@@ -509,35 +1384,116 @@ fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) -> Result<()> {
             .max()
             .unwrap_or(0)
             + 1;
-        let cells = item.data.iter().map(|c| Cell::from(c.value.to_string()));
+        let cells = app.selected_table.headers[col_start..col_end]
+            .iter()
+            .map(|h| {
+                let text = item
+                    .data
+                    .iter()
+                    .find(|kv| &kv.key == h)
+                    .map(|kv| kv.value.to_string())
+                    .unwrap_or_default();
+                Cell::from(text)
+            });
         Row::new(cells).height(height as u16).bottom_margin(1)
     });
 
-    let width = if !app.selected_table.headers.is_empty() {
-        20
-    } else {
-        0
-    };
-
-    let widths: Vec<Constraint> = app
-        .selected_table
-        .headers
+    let widths: Vec<Constraint> = app.selected_table.headers[col_start..col_end]
         .iter()
-        .take(3)
-        .map(|_h| Constraint::Length(width as u16))
+        .map(|_h| Constraint::Length(COLUMN_WIDTH))
         .collect();
+    let table_title = format!(
+        "Table{} (cols {}-{} / {})",
+        if app.loading_more { " (loading more…)" } else { "" },
+        (col_start + 1).min(total_cols.max(1)),
+        col_end,
+        total_cols
+    );
     let t = Table::new(rows)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Table"))
+        .block(Block::default().borders(Borders::ALL).title(table_title))
         .highlight_style(selected_style)
         .highlight_symbol(">> ")
-        .column_spacing(10)
+        .column_spacing(COLUMN_SPACING)
         .widths(&widths);
     f.render_stateful_widget(t, chunks[1], &mut app.items);
 
+    let (title, text) = match (app.input_mode, app.editing_purpose) {
+        (InputMode::Editing, Some(EditPurpose::Export)) => {
+            ("Export filename (Enter to save, Esc to cancel)", app.input.as_str())
+        }
+        (InputMode::Editing, _) => {
+            ("Editing (Enter to apply, Esc to cancel)", app.input.as_str())
+        }
+        (InputMode::Normal, _) => ("Normal (press / to filter, e to export, q to quit)", ""),
+    };
+    let input = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(input, outer[1]);
+
+    if let Some(detail) = &app.detail {
+        let area = centered_rect(60, 60, f.size());
+        let items: Vec<ListItem> = detail
+            .lines()
+            .iter()
+            .map(|(depth, key, value)| {
+                let indent = "  ".repeat(*depth);
+                let summary = match value {
+                    ItemValue::Map(m) => format!("Map ({} keys)", m.len()),
+                    ItemValue::List(l) => format!("List ({} items)", l.len()),
+                    other => other.to_string(),
+                };
+                ListItem::new(Spans::from(Span::raw(format!(
+                    "{}{}: {}",
+                    indent, key, summary
+                ))))
+            })
+            .collect();
+        let popup = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Detail — {}", detail.breadcrumb_path())),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+            .highlight_symbol(">> ");
+        let mut state = ListState::default();
+        state.select(Some(detail.selected));
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(popup, area, &mut state);
+    }
+
     Ok(())
 }
 
+/// Carves an `(percent_x, percent_y)`-sized rectangle out of the middle of
+/// `r`, the standard tui-rs recipe for centering a pop-up over existing
+/// content.
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Percentage((100 - percent_y) / 2),
+                Constraint::Percentage(percent_y),
+                Constraint::Percentage((100 - percent_y) / 2),
+            ]
+            .as_ref(),
+        )
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage((100 - percent_x) / 2),
+                Constraint::Percentage(percent_x),
+                Constraint::Percentage((100 - percent_x) / 2),
+            ]
+            .as_ref(),
+        )
+        .split(popup_layout[1])[1]
+}
+
 fn start_key_events() -> tokio::sync::mpsc::Receiver<Event<KeyEvent>> {
     let (tx, rx) = tokio::sync::mpsc::channel(1);
     let tick_rate = Duration::from_millis(200);
@@ -565,17 +1521,43 @@ fn start_key_events() -> tokio::sync::mpsc::Receiver<Event<KeyEvent>> {
     rx
 }
 
+#[derive(Clone)]
 struct DynamoClient {
     client: aws_sdk_dynamodb::Client,
 }
 
 impl DynamoClient {
-    pub async fn new(endpoint: &str) -> Self {
-        // Select a profile by setting the `AWS_PROFILE` environment variable.
-        let config = aws_config::load_from_env().await;
+    // Select a profile by setting the `AWS_PROFILE` environment variable.
+    pub async fn new(endpoint: &str) -> Result<Self> {
+        Self::from_profile(&ConnectionProfile {
+            name: "default".to_string(),
+            endpoint: endpoint.to_string(),
+            region: None,
+            profile: None,
+        })
+        .await
+    }
+
+    pub fn client(&self) -> &aws_sdk_dynamodb::Client {
+        &self.client
+    }
+
+    pub async fn from_profile(profile: &ConnectionProfile) -> Result<Self> {
+        let mut loader = aws_config::from_env();
+        if let Some(name) = &profile.profile {
+            loader = loader.profile_name(name);
+        }
+        if let Some(region) = &profile.region {
+            loader = loader.region(aws_sdk_dynamodb::Region::new(region.clone()));
+        }
+        let config = loader.load().await;
+
         let mut dynamodb_local_config = aws_sdk_dynamodb::config::Builder::from(&config);
-        if !endpoint.is_empty() {
-            let uri = endpoint.parse().unwrap();
+        if !profile.endpoint.is_empty() {
+            let uri: Uri = profile
+                .endpoint
+                .parse()
+                .map_err(|e| anyhow::anyhow!("invalid endpoint {:?}: {}", profile.endpoint, e))?;
             dynamodb_local_config =
                 dynamodb_local_config.endpoint_resolver(Endpoint::immutable(uri));
         }
@@ -583,10 +1565,6 @@ impl DynamoClient {
 
         let client = Client::from_conf(cfg);
 
-        Self { client }
-    }
-
-    pub fn client(&self) -> &aws_sdk_dynamodb::Client {
-        &self.client
+        Ok(Self { client })
     }
 }